@@ -78,7 +78,7 @@
 use std::borrow::Cow;
 use std::ffi::OsStr;
 use std::io::{Error as IoError, ErrorKind};
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 type Result<T, E = IoError> = core::result::Result<T, E>;
 
@@ -112,7 +112,7 @@ pub trait PathResolveExt {
     /// - It is unable to resolve the home directory for a tilde (`~`)
     ///
     /// See [`try_resolve`][`PathResolveExt::try_resolve`] for a non-panicking API.
-    fn resolve(&self) -> Cow<Path> {
+    fn resolve(&self) -> Cow<'_, Path> {
         self.try_resolve()
             .expect("should resolve path in current directory")
     }
@@ -123,7 +123,7 @@ pub trait PathResolveExt {
     ///
     /// - It is unable to detect the current working directory
     /// - It is unable to resolve the home directory for a tilde (`~`)
-    fn try_resolve(&self) -> Result<Cow<Path>> {
+    fn try_resolve(&self) -> Result<Cow<'_, Path>> {
         let cwd = std::env::current_dir()?;
         let resolved = self.try_resolve_in(&cwd)?;
         Ok(resolved)
@@ -149,31 +149,487 @@ pub trait PathResolveExt {
     /// unable to determine the home directory from the environment
     /// (using the `dirs` crate). See [`try_resolve_in`][`PathResolveExt::try_resolve_in`]
     /// for a non-panicking option.
-    fn resolve_in<P: AsRef<Path>>(&self, base: P) -> Cow<Path> {
+    fn resolve_in<P: AsRef<Path>>(&self, base: P) -> Cow<'_, Path> {
         self.try_resolve_in(base).expect("should resolve path")
     }
 
     /// Resolves this path against a given base path, returning an error
     /// if unable to resolve a home directory.
-    fn try_resolve_in<P: AsRef<Path>>(&self, base: P) -> Result<Cow<Path>>;
+    fn try_resolve_in<P: AsRef<Path>>(&self, base: P) -> Result<Cow<'_, Path>>;
+
+    /// Like [`resolve`][`PathResolveExt::resolve`], but first expands any
+    /// environment-variable components in the path.
+    ///
+    /// On Unix a component of the form `$VAR` or `${VAR}` is replaced with the
+    /// value of the environment variable `VAR`; on Windows a component of the
+    /// form `%VAR%` is used instead. Expansion happens before tilde and
+    /// relative resolution, so values like `$XDG_CONFIG_HOME/app/config.yml`
+    /// resolve the way a shell would.
+    ///
+    /// Referencing an unset variable is always an error; there is deliberately
+    /// no "expand to empty" mode, since a silently empty component would change
+    /// which directory a path resolves to.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`resolve`][`PathResolveExt::resolve`],
+    /// or if a referenced environment variable is not set. See
+    /// [`try_resolve_with_env`][`PathResolveExt::try_resolve_with_env`] for a
+    /// non-panicking API.
+    fn resolve_with_env(&self) -> Cow<'_, Path> {
+        self.try_resolve_with_env()
+            .expect("should resolve path with environment variables")
+    }
+
+    /// Attempts to resolve the path in the process's current directory,
+    /// expanding environment-variable components first.
+    ///
+    /// Returns an error under the same conditions as
+    /// [`try_resolve`][`PathResolveExt::try_resolve`], or if a referenced
+    /// environment variable is not set.
+    fn try_resolve_with_env(&self) -> Result<Cow<'_, Path>> {
+        let cwd = std::env::current_dir()?;
+        let resolved = self.try_resolve_in_with_env(&cwd)?;
+        Ok(resolved)
+    }
+
+    /// Like [`resolve_in`][`PathResolveExt::resolve_in`], but first expands any
+    /// environment-variable components in the path. See
+    /// [`resolve_with_env`][`PathResolveExt::resolve_with_env`] for the syntax.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as
+    /// [`resolve_in`][`PathResolveExt::resolve_in`], or if a referenced
+    /// environment variable is not set.
+    fn resolve_in_with_env<P: AsRef<Path>>(&self, base: P) -> Cow<'_, Path> {
+        self.try_resolve_in_with_env(base)
+            .expect("should resolve path with environment variables")
+    }
+
+    /// Resolves this path against a given base path, expanding
+    /// environment-variable components before tilde and relative resolution.
+    ///
+    /// Returns an [`ErrorKind::NotFound`] error if a referenced environment
+    /// variable is not set.
+    fn try_resolve_in_with_env<P: AsRef<Path>>(&self, base: P) -> Result<Cow<'_, Path>>;
+
+    /// Like [`absolutize_in`][`PathResolveExt::absolutize_in`], but anchored to
+    /// the process's current directory.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as
+    /// [`resolve`][`PathResolveExt::resolve`].
+    fn absolutize(&self) -> Cow<'_, Path> {
+        self.try_absolutize()
+            .expect("should absolutize path in current directory")
+    }
+
+    /// Attempts to anchor this path to the process's current directory and
+    /// lexically normalize it.
+    fn try_absolutize(&self) -> Result<Cow<'_, Path>> {
+        let cwd = std::env::current_dir()?;
+        let resolved = self.try_absolutize_in(&cwd)?;
+        Ok(resolved)
+    }
+
+    /// Resolves this path against a given base path and then lexically
+    /// normalizes the result, collapsing `.` and `..` segments.
+    ///
+    /// Unlike [`resolve_in`][`PathResolveExt::resolve_in`], this produces a
+    /// clean absolute path: [`Component::CurDir`] is dropped and
+    /// [`Component::ParentDir`] pops the preceding component, but the root is
+    /// never popped past (a leading `..` at the root is discarded rather than
+    /// escaping it). Normalization is purely lexical — the filesystem is not
+    /// touched and symlinks are not resolved. A trailing slash is preserved
+    /// only for paths that contain no `.`/`..` components.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as
+    /// [`resolve_in`][`PathResolveExt::resolve_in`].
+    fn absolutize_in<P: AsRef<Path>>(&self, base: P) -> Cow<'_, Path> {
+        self.try_absolutize_in(base)
+            .expect("should absolutize path")
+    }
+
+    /// Resolves this path against a given base path and then lexically
+    /// normalizes the result. See
+    /// [`absolutize_in`][`PathResolveExt::absolutize_in`] for the rules.
+    fn try_absolutize_in<P: AsRef<Path>>(&self, base: P) -> Result<Cow<'_, Path>>;
+
+    /// Like [`resolve_with_ndots`][`PathResolveExt::resolve_with_ndots`], but
+    /// anchored to a given base path.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as
+    /// [`resolve_in`][`PathResolveExt::resolve_in`].
+    fn resolve_in_with_ndots<P: AsRef<Path>>(&self, base: P) -> Cow<'_, Path> {
+        self.try_resolve_in_with_ndots(base)
+            .expect("should resolve path with ndots")
+    }
+
+    /// Like [`resolve`][`PathResolveExt::resolve`], but first expands "ndots"
+    /// components into repeated parent references.
+    ///
+    /// A component made up of three or more dots is rewritten before
+    /// resolution: `...` becomes `../..`, `....` becomes `../../..`, and so on
+    /// — each dot beyond the first contributes one level up. Plain `.` and
+    /// `..` components are left untouched.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as
+    /// [`resolve`][`PathResolveExt::resolve`].
+    fn resolve_with_ndots(&self) -> Cow<'_, Path> {
+        self.try_resolve_with_ndots()
+            .expect("should resolve path with ndots")
+    }
+
+    /// Attempts to resolve the path in the process's current directory,
+    /// expanding "ndots" components first. See
+    /// [`resolve_with_ndots`][`PathResolveExt::resolve_with_ndots`].
+    fn try_resolve_with_ndots(&self) -> Result<Cow<'_, Path>> {
+        let cwd = std::env::current_dir()?;
+        let resolved = self.try_resolve_in_with_ndots(&cwd)?;
+        Ok(resolved)
+    }
+
+    /// Resolves this path against a given base path, expanding "ndots"
+    /// components into repeated parent references before joining. See
+    /// [`resolve_with_ndots`][`PathResolveExt::resolve_with_ndots`] for the rules.
+    fn try_resolve_in_with_ndots<P: AsRef<Path>>(&self, base: P) -> Result<Cow<'_, Path>>;
+
+    /// Abbreviates a leading home-directory prefix back to `~`.
+    ///
+    /// This is the inverse of tilde resolution: given an absolute path under
+    /// the active user's home directory, the home prefix is replaced with `~`
+    /// (e.g. `/home/user/.vimrc` becomes `~/.vimrc`). A path equal to the home
+    /// directory becomes `~`, and a path that is not under the home directory
+    /// is returned unchanged. This is useful for displaying compact paths and
+    /// writing portable entries back into config files.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the home directory cannot be determined from the environment.
+    /// See [`try_abbreviate`][`PathResolveExt::try_abbreviate`] for a
+    /// non-panicking API.
+    fn abbreviate(&self) -> Cow<'_, Path> {
+        self.try_abbreviate().expect("should abbreviate path")
+    }
+
+    /// Attempts to abbreviate a leading home-directory prefix back to `~`.
+    ///
+    /// Returns an error if the home directory cannot be determined from the
+    /// environment. See [`abbreviate`][`PathResolveExt::abbreviate`] for the rules.
+    fn try_abbreviate(&self) -> Result<Cow<'_, Path>>;
+
+    /// Like [`resolve`][`PathResolveExt::resolve`], but returns a `Cow<str>`.
+    ///
+    /// This avoids `Path`↔`str` round-trips for string-based pipelines (config
+    /// values, for example): a borrowed result is returned without allocating
+    /// when the input is already absolute and valid UTF-8.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as
+    /// [`resolve`][`PathResolveExt::resolve`].
+    fn resolve_str(&self) -> Cow<'_, str> {
+        self.try_resolve_str().expect("should resolve path to str")
+    }
+
+    /// Attempts to resolve the path in the process's current directory,
+    /// returning a `Cow<str>`. See
+    /// [`resolve_str`][`PathResolveExt::resolve_str`].
+    ///
+    /// Returns an [`ErrorKind::InvalidData`] error if the path is not valid UTF-8.
+    fn try_resolve_str(&self) -> Result<Cow<'_, str>> {
+        let cwd = std::env::current_dir()?;
+        self.try_resolve_str_in(&cwd)
+    }
+
+    /// Like [`resolve_in`][`PathResolveExt::resolve_in`], but returns a
+    /// `Cow<str>`. See [`resolve_str`][`PathResolveExt::resolve_str`].
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as
+    /// [`resolve_in`][`PathResolveExt::resolve_in`].
+    fn resolve_str_in<P: AsRef<Path>>(&self, base: P) -> Cow<'_, str> {
+        self.try_resolve_str_in(base)
+            .expect("should resolve path to str")
+    }
+
+    /// Resolves this path against a given base path, returning a `Cow<str>`.
+    ///
+    /// The fast path is preserved: when resolution borrows the input (it was
+    /// already absolute) and the input is valid UTF-8, the input is handed back
+    /// borrowed with no allocation. A `String` is only allocated when
+    /// resolution produced an owned path. Returns an [`ErrorKind::InvalidData`]
+    /// error if the input or resolved path is not valid UTF-8.
+    fn try_resolve_str_in<P: AsRef<Path>>(&self, base: P) -> Result<Cow<'_, str>>;
 }
 
 impl<T: AsRef<OsStr>> PathResolveExt for T {
-    fn try_resolve_in<P: AsRef<Path>>(&self, base: P) -> Result<Cow<Path>> {
+    fn try_resolve_in<P: AsRef<Path>>(&self, base: P) -> Result<Cow<'_, Path>> {
         try_resolve_path(base.as_ref(), Path::new(self))
     }
+
+    fn try_resolve_in_with_env<P: AsRef<Path>>(&self, base: P) -> Result<Cow<'_, Path>> {
+        let path = Path::new(self);
+        match expand_env_vars(path)? {
+            // Nothing to expand, resolve the borrowed path directly
+            Cow::Borrowed(path) => try_resolve_path(base.as_ref(), path),
+            // Expansion allocated, so resolve the owned path and keep it owned
+            Cow::Owned(expanded) => {
+                let resolved = try_resolve_path(base.as_ref(), &expanded)?;
+                Ok(Cow::Owned(resolved.into_owned()))
+            }
+        }
+    }
+
+    fn try_absolutize_in<P: AsRef<Path>>(&self, base: P) -> Result<Cow<'_, Path>> {
+        match self.try_resolve_in(base)? {
+            // Anchoring borrowed the original path, so it may still borrow
+            Cow::Borrowed(resolved) => Ok(normalize_lexically(resolved)),
+            // Anchoring allocated, so keep the normalized result owned
+            Cow::Owned(resolved) => Ok(Cow::Owned(normalize_lexically(&resolved).into_owned())),
+        }
+    }
+
+    fn try_resolve_in_with_ndots<P: AsRef<Path>>(&self, base: P) -> Result<Cow<'_, Path>> {
+        let path = Path::new(self);
+        match expand_ndots(path) {
+            // Nothing to expand, resolve the borrowed path directly
+            Cow::Borrowed(path) => try_resolve_path(base.as_ref(), path),
+            // Expansion allocated, so resolve the owned path and keep it owned
+            Cow::Owned(expanded) => {
+                let resolved = try_resolve_path(base.as_ref(), &expanded)?;
+                Ok(Cow::Owned(resolved.into_owned()))
+            }
+        }
+    }
+
+    fn try_abbreviate(&self) -> Result<Cow<'_, Path>> {
+        let home = home_dir().ok_or_else(|| IoError::new(ErrorKind::NotFound, "homedir not found"))?;
+        Ok(abbreviate_with_home(&home, Path::new(self)))
+    }
+
+    fn try_resolve_str_in<P: AsRef<Path>>(&self, base: P) -> Result<Cow<'_, str>> {
+        match self.try_resolve_in(base)? {
+            // Resolution borrowed the input, so hand back the input as `&str`
+            // without allocating (the borrowed path is the input unchanged).
+            Cow::Borrowed(_) => match self.as_ref().to_str() {
+                Some(resolved) => Ok(Cow::Borrowed(resolved)),
+                None => Err(IoError::new(
+                    ErrorKind::InvalidData,
+                    "path is not valid UTF-8",
+                )),
+            },
+            // Resolution allocated, so turn the owned path into a `String`.
+            // Rebuild it from its components first so interior `.` artifacts
+            // left by joining (e.g. `.app/./config.yml`) don't leak into the
+            // string; `Path` comparisons already ignore them for the
+            // `Cow<Path>` variant.
+            Cow::Owned(resolved) => {
+                let normalized: PathBuf = resolved.components().collect();
+                match normalized.into_os_string().into_string() {
+                    Ok(resolved) => Ok(Cow::Owned(resolved)),
+                    Err(_) => Err(IoError::new(
+                        ErrorKind::InvalidData,
+                        "resolved path is not valid UTF-8",
+                    )),
+                }
+            }
+        }
+    }
+}
+
+/// Abbreviate a leading home-directory prefix in a path back to `~`.
+///
+/// - If the path is not under `home`, returns the original path
+/// - If the path equals `home`, returns `~`
+/// - Otherwise replaces the home prefix with `~` and keeps the remainder
+///
+/// # Example
+///
+/// ```ignore
+/// # use std::path::{Path, PathBuf};
+/// # use resolve_path::abbreviate_with_home;
+/// assert_eq!(abbreviate_with_home(Path::new("/home/test"), Path::new("/home/test/.vimrc")), Path::new("~/.vimrc"));
+/// assert_eq!(abbreviate_with_home(Path::new("/home/test"), Path::new("/home/test")), Path::new("~"));
+/// assert_eq!(abbreviate_with_home(Path::new("/home/test"), Path::new("/etc/hosts")), Path::new("/etc/hosts"));
+/// ```
+fn abbreviate_with_home<'a>(home: &Path, path: &'a Path) -> Cow<'a, Path> {
+    match path.strip_prefix(home) {
+        // The remainder is always relative, so `join` never yields `~//...`
+        Ok(rest) if rest.as_os_str().is_empty() => Cow::Owned(PathBuf::from("~")),
+        Ok(rest) => Cow::Owned(Path::new("~").join(rest)),
+        Err(_) => Cow::Borrowed(path),
+    }
+}
+
+/// Expand "ndots" components into repeated [`Component::ParentDir`] references.
+///
+/// A component made up of three or more dots is rewritten: `...` becomes
+/// `../..`, `....` becomes `../../..`, and so on. Plain `.` and `..` are left
+/// untouched, as are non-UTF-8 components. If no component is an ndots the path
+/// is returned borrowed, otherwise the expanded path is returned owned.
+fn expand_ndots(path: &Path) -> Cow<'_, Path> {
+    // Stay borrowed unless some component is an ndots
+    if !path
+        .components()
+        .any(|component| ndots_levels(component.as_os_str()).is_some())
+    {
+        return Cow::Borrowed(path);
+    }
+
+    let mut expanded = PathBuf::new();
+    for component in path.components() {
+        match ndots_levels(component.as_os_str()) {
+            Some(levels) => {
+                for _ in 0..levels {
+                    expanded.push(Component::ParentDir.as_os_str());
+                }
+            }
+            None => expanded.push(component.as_os_str()),
+        }
+    }
+    Cow::Owned(expanded)
+}
+
+/// If the component is an "ndots" (three or more dots), return how many parent
+/// levels it represents, which is one fewer than the number of dots.
+///
+/// Returns `None` for `.`, `..`, non-UTF-8 components, and anything that is not
+/// made up entirely of dots.
+fn ndots_levels(component: &OsStr) -> Option<usize> {
+    let component = component.to_str()?;
+    if component.len() >= 3 && component.bytes().all(|byte| byte == b'.') {
+        Some(component.len() - 1)
+    } else {
+        None
+    }
+}
+
+/// Lexically normalize a path, collapsing `.` and `..` segments.
+///
+/// [`Component::CurDir`] is dropped and [`Component::ParentDir`] pops the
+/// preceding normal component, but the root prefix is never popped past. No
+/// filesystem access is performed. If the path contains no `.`/`..` components
+/// it is returned borrowed (preserving any trailing slash), otherwise the
+/// normalized path is returned owned.
+fn normalize_lexically(path: &Path) -> Cow<'_, Path> {
+    // Stay borrowed unless there is something to collapse. `components()` skips
+    // interior `.` segments, so look for `.`/`..` in the path's text instead.
+    if !path
+        .to_string_lossy()
+        .split(std::path::is_separator)
+        .any(|segment| segment == "." || segment == "..")
+    {
+        return Cow::Borrowed(path);
+    }
+
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::Prefix(_) | Component::RootDir => normalized.push(component.as_os_str()),
+            Component::CurDir => {}
+            Component::ParentDir => match normalized.components().next_back() {
+                // Pop the preceding normal component
+                Some(Component::Normal(_)) => {
+                    normalized.pop();
+                }
+                // Never pop past the root; a leading `..` at the root is discarded
+                Some(Component::RootDir) | Some(Component::Prefix(_)) => {}
+                // Otherwise this is a leading `..` on a relative path; keep it
+                _ => normalized.push(component.as_os_str()),
+            },
+            Component::Normal(c) => normalized.push(c),
+        }
+    }
+    Cow::Owned(normalized)
+}
+
+/// Expand environment-variable components in the given path.
+///
+/// On Unix a component of the form `$VAR` or `${VAR}` is replaced with the
+/// value of the environment variable `VAR`; on Windows a component of the form
+/// `%VAR%` is used instead. If no component references a variable the path is
+/// returned borrowed, otherwise the expanded path is returned owned.
+///
+/// An unset variable is always an error (there is no "expand to empty" mode):
+/// returns an [`ErrorKind::NotFound`] error if a referenced variable is not set.
+fn expand_env_vars(path: &Path) -> Result<Cow<'_, Path>> {
+    // Stay borrowed unless some component actually references a variable
+    if !path
+        .components()
+        .any(|component| env_var_name(component.as_os_str()).is_some())
+    {
+        return Ok(Cow::Borrowed(path));
+    }
+
+    let mut expanded = PathBuf::new();
+    for component in path.components() {
+        match env_var_name(component.as_os_str()) {
+            Some(name) => {
+                let value = std::env::var(name).map_err(|_| {
+                    IoError::new(
+                        ErrorKind::NotFound,
+                        format!("environment variable `{name}` is not set"),
+                    )
+                })?;
+                // Past the first component, trim leading separators so an
+                // absolute value does not replace the components before it
+                if expanded.as_os_str().is_empty() {
+                    expanded.push(value);
+                } else {
+                    expanded.push(value.trim_start_matches(std::path::is_separator));
+                }
+            }
+            None => expanded.push(component.as_os_str()),
+        }
+    }
+    Ok(Cow::Owned(expanded))
+}
+
+/// If the component names an environment variable, return the variable name.
+///
+/// Recognizes `$VAR` and `${VAR}` on Unix and `%VAR%` on Windows. Returns
+/// `None` for non-UTF-8 components or components that are not variables.
+#[cfg(not(windows))]
+fn env_var_name(component: &OsStr) -> Option<&str> {
+    let component = component.to_str()?;
+    let rest = component.strip_prefix('$')?;
+    match rest.strip_prefix('{') {
+        Some(inner) => inner.strip_suffix('}'),
+        None => Some(rest),
+    }
+}
+
+/// If the component names an environment variable, return the variable name.
+///
+/// Recognizes `$VAR` and `${VAR}` on Unix and `%VAR%` on Windows. Returns
+/// `None` for non-UTF-8 components or components that are not variables.
+#[cfg(windows)]
+fn env_var_name(component: &OsStr) -> Option<&str> {
+    let component = component.to_str()?;
+    component.strip_prefix('%')?.strip_suffix('%')
 }
 
 fn try_resolve_path<'a>(base: &Path, to_resolve: &'a Path) -> Result<Cow<'a, Path>> {
     // If the path to resolve is absolute, there's no relativity to resolve
     if to_resolve.is_absolute() {
-        return Ok(Cow::Borrowed(to_resolve));
+        return Ok(normalize_verbatim(Cow::Borrowed(to_resolve)));
     }
 
-    // If the path to resolve has a tilde, resolve it to home and be done
-    if to_resolve.starts_with(Path::new("~")) {
+    // If the path to resolve has a tilde, resolve it to home and be done.
+    // Check the text, not the first component, so that `~name` is covered too.
+    if matches!(to_resolve.to_str(), Some(p) if p.starts_with('~')) {
         let resolved = resolve_tilde(to_resolve)?;
-        return Ok(resolved);
+        return Ok(normalize_verbatim(resolved));
     }
 
     // Resolve the base path by expanding tilde if needed
@@ -216,14 +672,52 @@ fn try_resolve_path<'a>(base: &Path, to_resolve: &'a Path) -> Result<Cow<'a, Pat
     };
 
     let resolved = base_directory.join(to_resolve);
-    Ok(Cow::Owned(resolved))
+    Ok(normalize_verbatim(Cow::Owned(resolved)))
+}
+
+/// Normalize away a Windows verbatim prefix, keeping ownership otherwise.
+fn normalize_verbatim(path: Cow<'_, Path>) -> Cow<'_, Path> {
+    match strip_verbatim_prefix(&path) {
+        // The prefix was stripped, so hand back the cleaned owned path
+        Cow::Owned(stripped) => Cow::Owned(stripped),
+        // Nothing to strip; keep the original path and its ownership
+        Cow::Borrowed(_) => path,
+    }
+}
+
+/// Strip a Windows verbatim prefix from a path.
+///
+/// Removes a leading `\\?\` prefix, and rewrites `\\?\UNC\` back to `\\`, so
+/// that resolved paths display and compare the way users expect. Paths without
+/// a verbatim prefix — and all paths on non-Windows platforms — are returned
+/// borrowed.
+#[cfg(windows)]
+fn strip_verbatim_prefix(path: &Path) -> Cow<'_, Path> {
+    let path_str = match path.to_str() {
+        Some(path_str) => path_str,
+        None => return Cow::Borrowed(path),
+    };
+
+    if let Some(rest) = path_str.strip_prefix(r"\\?\UNC\") {
+        return Cow::Owned(PathBuf::from(format!(r"\\{rest}")));
+    }
+    if let Some(rest) = path_str.strip_prefix(r"\\?\") {
+        return Cow::Owned(PathBuf::from(rest));
+    }
+    Cow::Borrowed(path)
+}
+
+#[cfg(not(windows))]
+fn strip_verbatim_prefix(path: &Path) -> Cow<'_, Path> {
+    Cow::Borrowed(path)
 }
 
 /// Resolve a tilde in the given path to the home directory, if a tilde is present.
 ///
 /// - If the path does not begin with a tilde, returns the original path
 /// - If the path is not valid UTF-8, returns the original path
-/// - If the tilde names another user (e.g. `~user`), returns the original path
+/// - If the tilde names another user (e.g. `~user`), resolves that user's home
+///   directory, or returns an [`ErrorKind::NotFound`] error if no such user exists
 /// - Otherwise, resolves the tilde to the homedir and joins with the remaining path
 ///
 /// # Example
@@ -236,11 +730,52 @@ fn try_resolve_path<'a>(base: &Path, to_resolve: &'a Path) -> Result<Cow<'a, Pat
 /// assert_eq!(resolve_tilde(Path::new("/tmp/hello")).unwrap(), Path::new("/tmp/hello"));
 /// assert_eq!(resolve_tilde(Path::new("./configure")).unwrap(), Path::new("./configure"));
 /// ```
-fn resolve_tilde(path: &Path) -> Result<Cow<Path>> {
+fn resolve_tilde(path: &Path) -> Result<Cow<'_, Path>> {
+    // A `~name` prefix names another user's home directory
+    if let Some(resolved) = resolve_tilde_user(path)? {
+        return Ok(resolved);
+    }
     let home = home_dir().ok_or_else(|| IoError::new(ErrorKind::NotFound, "homedir not found"))?;
     Ok(resolve_tilde_with_home(home, path))
 }
 
+/// Resolve a `~name` prefix to the named user's home directory.
+///
+/// Returns `Ok(None)` if the path is not a `~name`-style path (a plain `~`,
+/// `~/...`, a non-tilde path, or non-UTF-8); returns an [`ErrorKind::NotFound`]
+/// error if the named user has no home directory.
+fn resolve_tilde_user(path: &Path) -> Result<Option<Cow<'_, Path>>> {
+    let stripped = match path.to_str().and_then(|p| p.strip_prefix('~')) {
+        Some(stripped) => stripped,
+        None => return Ok(None),
+    };
+
+    // A plain `~` or `~/...` is resolved against the active user's home dir
+    if stripped.is_empty() || stripped.starts_with('/') {
+        return Ok(None);
+    }
+
+    // Split a `~name/rest` into the user name and the remaining path
+    let (name, rest) = match stripped.split_once('/') {
+        Some((name, rest)) => (name, rest),
+        None => (stripped, ""),
+    };
+
+    let home = user_home_dir(name).ok_or_else(|| {
+        IoError::new(
+            ErrorKind::NotFound,
+            format!("no home directory for user `{name}`"),
+        )
+    })?;
+
+    let resolved = if rest.is_empty() {
+        home
+    } else {
+        home.join(rest.trim_start_matches('/'))
+    };
+    Ok(Some(Cow::Owned(resolved)))
+}
+
 /// Resolve a tilde in a given path to a _given_ home directory.
 ///
 /// - If the path does not begin with a tilde, returns the original path
@@ -258,7 +793,7 @@ fn resolve_tilde(path: &Path) -> Result<Cow<Path>> {
 /// assert_eq!(resolve_tilde_with_home(PathBuf::from("/home/test"), Path::new("/tmp/hello")), Path::new("/tmp/hello"));
 /// assert_eq!(resolve_tilde_with_home(PathBuf::from("/home/test"), Path::new("./configure")), Path::new("./configure"));
 /// ```
-fn resolve_tilde_with_home(home: PathBuf, path: &Path) -> Cow<Path> {
+fn resolve_tilde_with_home(home: PathBuf, path: &Path) -> Cow<'_, Path> {
     // If this path has no tilde, return it as-is
     if !path.starts_with(Path::new("~")) {
         return Cow::Borrowed(path);
@@ -300,6 +835,39 @@ fn home_dir() -> Option<PathBuf> {
     Some(PathBuf::from("/home/test"))
 }
 
+/// Look up a named user's home directory from the passwd database.
+///
+/// Returns `None` if the user does not exist. On non-Unix platforms, where
+/// there is no passwd database, this always returns `None`.
+#[cfg(all(unix, not(test)))]
+fn user_home_dir(name: &str) -> Option<PathBuf> {
+    let passwd = std::fs::read_to_string("/etc/passwd").ok()?;
+    passwd.lines().find_map(|line| {
+        // Fields are name:passwd:uid:gid:gecos:home:shell
+        let mut fields = line.split(':');
+        if fields.next() == Some(name) {
+            fields.nth(4).map(PathBuf::from)
+        } else {
+            None
+        }
+    })
+}
+
+#[cfg(all(not(unix), not(test)))]
+fn user_home_dir(_name: &str) -> Option<PathBuf> {
+    None
+}
+
+/// During testing, resolve a small fixed set of users.
+#[cfg(test)]
+fn user_home_dir(name: &str) -> Option<PathBuf> {
+    match name {
+        "test" => Some(PathBuf::from("/home/test")),
+        "other" => Some(PathBuf::from("/home/other")),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -405,6 +973,178 @@ mod tests {
         assert_eq!("garbage.txt".resolve(), Path::new("/tmp/garbage.txt"));
     }
 
+    #[cfg(not(windows))]
+    #[test]
+    fn test_resolve_with_env() {
+        std::env::set_var("RESOLVE_PATH_XDG", "/home/test/.config");
+        assert_eq!(
+            "$RESOLVE_PATH_XDG/app/config.yml".resolve_in_with_env("/tmp"),
+            Path::new("/home/test/.config/app/config.yml")
+        );
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn test_resolve_with_env_braces() {
+        std::env::set_var("RESOLVE_PATH_BRACED", "/opt/data");
+        assert_eq!(
+            "${RESOLVE_PATH_BRACED}/file".resolve_in_with_env("/tmp"),
+            Path::new("/opt/data/file")
+        );
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn test_resolve_with_env_missing() {
+        let result = "$RESOLVE_PATH_DEFINITELY_UNSET/x".try_resolve_in_with_env("/tmp");
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_resolve_with_env_no_vars_borrowed() {
+        assert!(matches!(
+            expand_env_vars(Path::new("/plain/path")),
+            Ok(Cow::Borrowed(_))
+        ));
+    }
+
+    #[test]
+    fn test_absolutize_collapses_parent() {
+        assert_eq!(
+            "../.app2/config.yml".absolutize_in("/home/user/.app"),
+            Path::new("/home/user/.app2/config.yml")
+        );
+    }
+
+    #[test]
+    fn test_absolutize_collapses_curdir() {
+        assert_eq!(
+            "./a/./b/./c".absolutize_in("/home/user"),
+            Path::new("/home/user/a/b/c")
+        );
+    }
+
+    #[test]
+    fn test_absolutize_never_escapes_root() {
+        assert_eq!(
+            "../../../..".absolutize_in("/"),
+            Path::new("/")
+        );
+    }
+
+    #[test]
+    fn test_absolutize_already_normalized_borrowed() {
+        assert!(matches!(
+            normalize_lexically(Path::new("/home/user/")),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn test_resolve_ndots_three() {
+        assert_eq!(
+            ".../config.yml".resolve_in_with_ndots("/home/user/.app"),
+            Path::new("/home/user/.app/../../config.yml")
+        );
+    }
+
+    #[test]
+    fn test_resolve_ndots_four() {
+        assert_eq!(
+            "....".resolve_in_with_ndots("/a/b/c/d"),
+            Path::new("/a/b/c/d/../../..")
+        );
+    }
+
+    #[test]
+    fn test_resolve_ndots_leaves_dotdot() {
+        assert_eq!(
+            "../x".resolve_in_with_ndots("/home/user"),
+            Path::new("/home/user/../x")
+        );
+        assert!(matches!(
+            expand_ndots(Path::new("./a/../b")),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn test_abbreviate_home_path() {
+        assert_eq!("/home/test/.vimrc".abbreviate(), Path::new("~/.vimrc"));
+    }
+
+    #[test]
+    fn test_abbreviate_exact_home() {
+        assert_eq!("/home/test".abbreviate(), Path::new("~"));
+    }
+
+    #[test]
+    fn test_abbreviate_outside_home() {
+        assert_eq!("/etc/hosts".abbreviate(), Path::new("/etc/hosts"));
+    }
+
+    #[test]
+    fn test_resolve_tilde_user() {
+        assert_eq!(
+            "~other/shared/file".resolve(),
+            Path::new("/home/other/shared/file")
+        );
+    }
+
+    #[test]
+    fn test_resolve_tilde_user_no_rest() {
+        assert_eq!("~other".resolve(), Path::new("/home/other"));
+    }
+
+    #[test]
+    fn test_resolve_tilde_user_not_found() {
+        let result = "~nobodyhere/x".try_resolve();
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::NotFound);
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn test_strip_verbatim_noop_on_unix() {
+        assert!(matches!(
+            strip_verbatim_prefix(Path::new("/home/user/file")),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_strip_verbatim_disk() {
+        assert_eq!(
+            strip_verbatim_prefix(Path::new(r"\\?\C:\Users\me")),
+            Path::new(r"C:\Users\me")
+        );
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_strip_verbatim_unc() {
+        assert_eq!(
+            strip_verbatim_prefix(Path::new(r"\\?\UNC\server\share")),
+            Path::new(r"\\server\share")
+        );
+    }
+
+    #[test]
+    fn test_resolve_str_in_owned() {
+        assert_eq!(
+            "./config.yml".resolve_str_in("/home/user/.app"),
+            "/home/user/.app/config.yml"
+        );
+    }
+
+    #[test]
+    fn test_resolve_str_in_borrowed() {
+        assert!(matches!(
+            "/etc/hosts".try_resolve_str_in("/tmp").unwrap(),
+            Cow::Borrowed("/etc/hosts")
+        ));
+    }
+
     #[test]
     fn test_resolve_base_file() {
         let base_path = "/tmp/path-resolve-test.txt";